@@ -0,0 +1,62 @@
+// boot-rust/src/config.rs
+
+use std::env;
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::warn;
+
+/// Env var overriding the largest gRPC request (in bytes) the server will decode.
+const MAX_MESSAGE_BYTES_ENV: &str = "BOOT_MAX_MESSAGE_BYTES";
+
+/// Large enough for review passes carrying a whole generated project.
+const DEFAULT_MAX_MESSAGE_BYTES: usize = 64 * 1024 * 1024;
+
+/// Env var capping how many requests the server handles at once, across all connections.
+const MAX_CONCURRENT_REQUESTS_ENV: &str = "BOOT_MAX_CONCURRENT_REQUESTS";
+
+const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 32;
+
+/// Env var bounding how long a single request may run, in seconds.
+const REQUEST_TIMEOUT_SECS_ENV: &str = "BOOT_REQUEST_TIMEOUT_SECS";
+
+const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// Transport settings for the tonic server, read from the environment at startup.
+#[derive(Debug, Clone)]
+pub struct ServerConfig {
+    pub max_message_bytes: usize,
+    pub max_concurrent_requests: usize,
+    pub request_timeout: Duration,
+}
+
+impl ServerConfig {
+    pub fn from_env() -> Self {
+        Self {
+            // Limits of zero would reject every request, so both are clamped to at least one.
+            max_message_bytes: env_or(MAX_MESSAGE_BYTES_ENV, DEFAULT_MAX_MESSAGE_BYTES).max(1),
+            max_concurrent_requests: env_or(
+                MAX_CONCURRENT_REQUESTS_ENV,
+                DEFAULT_MAX_CONCURRENT_REQUESTS,
+            )
+            .max(1),
+            request_timeout: Duration::from_secs(env_or(
+                REQUEST_TIMEOUT_SECS_ENV,
+                DEFAULT_REQUEST_TIMEOUT_SECS,
+            )),
+        }
+    }
+}
+
+/// Parses `key` from the environment, falling back to `default` when unset or invalid.
+fn env_or<T: FromStr + Copy + std::fmt::Debug>(key: &str, default: T) -> T {
+    match env::var(key) {
+        Ok(value) => value.trim().parse().unwrap_or_else(|_| {
+            warn!(
+                "Invalid {} value {:?}; using default {:?}",
+                key, value, default
+            );
+            default
+        }),
+        Err(_) => default,
+    }
+}
@@ -0,0 +1,193 @@
+// boot-rust/src/error.rs
+
+use prost::Message;
+use std::fmt;
+use tonic::metadata::MetadataValue;
+use tonic::{Code, Status};
+
+/// Metadata key carrying the machine-readable reason of a failed call, for hosts
+/// that don't decode the status details.
+pub const ERROR_REASON_KEY: &str = "x-boot-error-reason";
+
+/// The `google.rpc.ErrorInfo` domain identifying errors raised by this plugin.
+const ERROR_DOMAIN: &str = "boot-rust";
+
+const ERROR_INFO_TYPE_URL: &str = "type.googleapis.com/google.rpc.ErrorInfo";
+
+/// `google.rpc.Status`, the standard payload of gRPC status details.
+#[derive(Clone, PartialEq, Message)]
+struct RpcStatus {
+    #[prost(int32, tag = "1")]
+    code: i32,
+    #[prost(string, tag = "2")]
+    message: String,
+    #[prost(message, repeated, tag = "3")]
+    details: Vec<ProtoAny>,
+}
+
+/// `google.protobuf.Any`.
+#[derive(Clone, PartialEq, Message)]
+struct ProtoAny {
+    #[prost(string, tag = "1")]
+    type_url: String,
+    #[prost(bytes = "vec", tag = "2")]
+    value: Vec<u8>,
+}
+
+/// `google.rpc.ErrorInfo` (the `metadata` map is omitted; it is never set).
+#[derive(Clone, PartialEq, Message)]
+struct ErrorInfo {
+    #[prost(string, tag = "1")]
+    reason: String,
+    #[prost(string, tag = "2")]
+    domain: String,
+}
+
+/// Errors surfaced to `boot-core` at the gRPC boundary.
+///
+/// Internals keep using `anyhow`; handlers classify failures into one of these
+/// variants so the host can tell a bad spec from a plugin-side fault.
+#[derive(Debug)]
+pub enum BootError {
+    /// The `spec.toml` sent by the host could not be parsed or is missing fields.
+    SpecError(String),
+    /// The prompts directory does not exist where the plugin expects it.
+    PromptsNotFound(String),
+    /// The prompts directory exists but one of its entries could not be read.
+    PromptsError(String),
+    /// The loaded prompts don't match `BOOT_PROMPTS_CHECKSUM` in strict mode.
+    PromptsChecksumMismatch(String),
+    /// Anything else that went wrong inside the plugin.
+    Internal(String),
+}
+
+impl fmt::Display for BootError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BootError::SpecError(msg) => write!(f, "Invalid spec: {}", msg),
+            BootError::PromptsNotFound(msg) => write!(f, "Prompts not found: {}", msg),
+            BootError::PromptsError(msg) => write!(f, "Failed to load prompts: {}", msg),
+            BootError::PromptsChecksumMismatch(msg) => write!(f, "Prompts rejected: {}", msg),
+            BootError::Internal(msg) => write!(f, "Internal error: {}", msg),
+        }
+    }
+}
+
+impl std::error::Error for BootError {}
+
+impl BootError {
+    pub fn spec(err: anyhow::Error) -> Self {
+        BootError::SpecError(err.to_string())
+    }
+
+    pub fn prompts_not_found(err: anyhow::Error) -> Self {
+        BootError::PromptsNotFound(err.to_string())
+    }
+
+    pub fn prompts(err: anyhow::Error) -> Self {
+        BootError::PromptsError(err.to_string())
+    }
+
+    pub fn code(&self) -> Code {
+        match self {
+            BootError::SpecError(_) => Code::InvalidArgument,
+            BootError::PromptsNotFound(_) | BootError::PromptsChecksumMismatch(_) => {
+                Code::FailedPrecondition
+            }
+            BootError::PromptsError(_) | BootError::Internal(_) => Code::Internal,
+        }
+    }
+
+    /// A stable, machine-readable reason string for this error.
+    pub fn reason(&self) -> &'static str {
+        match self {
+            BootError::SpecError(_) => "INVALID_SPEC",
+            BootError::PromptsNotFound(_) => "PROMPTS_NOT_FOUND",
+            BootError::PromptsError(_) => "PROMPTS_UNREADABLE",
+            BootError::PromptsChecksumMismatch(_) => "PROMPTS_CHECKSUM_MISMATCH",
+            BootError::Internal(_) => "INTERNAL",
+        }
+    }
+}
+
+impl From<BootError> for Status {
+    /// Builds a status whose details hold a `google.rpc.ErrorInfo` with the
+    /// error's reason; the reason is also mirrored into metadata.
+    fn from(err: BootError) -> Self {
+        let code = err.code();
+        let message = err.to_string();
+        let info = ErrorInfo {
+            reason: err.reason().to_string(),
+            domain: ERROR_DOMAIN.to_string(),
+        };
+        let details = RpcStatus {
+            code: code as i32,
+            message: message.clone(),
+            details: vec![ProtoAny {
+                type_url: ERROR_INFO_TYPE_URL.to_string(),
+                value: info.encode_to_vec(),
+            }],
+        };
+
+        let mut status = Status::with_details(code, message, details.encode_to_vec().into());
+        status
+            .metadata_mut()
+            .insert(ERROR_REASON_KEY, MetadataValue::from_static(err.reason()));
+        status
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn maps_variants_to_codes_and_reasons() {
+        let cases = [
+            (
+                BootError::SpecError("x".into()),
+                Code::InvalidArgument,
+                "INVALID_SPEC",
+            ),
+            (
+                BootError::PromptsNotFound("x".into()),
+                Code::FailedPrecondition,
+                "PROMPTS_NOT_FOUND",
+            ),
+            (
+                BootError::PromptsError("x".into()),
+                Code::Internal,
+                "PROMPTS_UNREADABLE",
+            ),
+            (
+                BootError::PromptsChecksumMismatch("x".into()),
+                Code::FailedPrecondition,
+                "PROMPTS_CHECKSUM_MISMATCH",
+            ),
+            (BootError::Internal("x".into()), Code::Internal, "INTERNAL"),
+        ];
+        for (err, code, reason) in cases {
+            assert_eq!(err.code(), code);
+            assert_eq!(err.reason(), reason);
+        }
+    }
+
+    #[test]
+    fn status_carries_error_info_details_and_reason_metadata() {
+        let status = Status::from(BootError::SpecError("missing field `name`".into()));
+        assert_eq!(status.code(), Code::InvalidArgument);
+        assert_eq!(status.message(), "Invalid spec: missing field `name`");
+        assert_eq!(
+            status.metadata().get(ERROR_REASON_KEY).unwrap(),
+            "INVALID_SPEC"
+        );
+
+        let details = RpcStatus::decode(status.details()).unwrap();
+        assert_eq!(details.code, Code::InvalidArgument as i32);
+        assert_eq!(details.details.len(), 1);
+        assert_eq!(details.details[0].type_url, ERROR_INFO_TYPE_URL);
+        let info = ErrorInfo::decode(details.details[0].value.as_slice()).unwrap();
+        assert_eq!(info.reason, "INVALID_SPEC");
+        assert_eq!(info.domain, ERROR_DOMAIN);
+    }
+}
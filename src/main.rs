@@ -1,10 +1,18 @@
 use std::net::SocketAddr;
 use tokio_stream::wrappers::TcpListenerStream;
+use tonic::codec::CompressionEncoding;
 use tonic::transport::Server;
+use tower::limit::GlobalConcurrencyLimitLayer;
+use tracing::{error, info};
 
-// Only the server module is needed now.
+mod config;
+mod error;
+mod prompts;
 mod server;
+mod spec;
 
+use config::ServerConfig;
+use prompts::PromptStore;
 use server::MyBootCodePlugin as RustPlugin;
 
 use crate::boot_plugin::boot_code_plugin_server::BootCodePluginServer;
@@ -28,6 +36,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Keep stdout clean for the handshake required by boot-core.
     tracing_subscriber::fmt().with_writer(std::io::stderr).init();
     install_panic_hook();
+    info!(
+        "{} v{} starting",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION")
+    );
+
+    // Validate the prompts before the handshake so misconfiguration fails fast.
+    let prompts = PromptStore::from_env();
+    if let Err(e) = prompts.warm_up() {
+        error!("boot-rust failed to start: {}", e);
+        return Err(e.into());
+    }
 
     // Bind to an ephemeral port on the loopback address.
     let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await?;
@@ -36,11 +56,18 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     // Print the handshake line to stdout.
     println!("1|1|tcp|{}:{}|grpc", addr.ip(), addr.port());
 
-    // The server struct from `src/server.rs` no longer needs arguments.
-    let plugin_service = RustPlugin::default();
-    let server = BootCodePluginServer::new(plugin_service);
+    let config = ServerConfig::from_env();
+    let plugin_service = RustPlugin::new(prompts);
+    let server = BootCodePluginServer::new(plugin_service)
+        .accept_compressed(CompressionEncoding::Gzip)
+        .send_compressed(CompressionEncoding::Gzip)
+        .max_decoding_message_size(config.max_message_bytes);
 
     Server::builder()
+        .timeout(config.request_timeout)
+        .layer(GlobalConcurrencyLimitLayer::new(
+            config.max_concurrent_requests,
+        ))
         .add_service(server)
         .serve_with_incoming(TcpListenerStream::new(listener))
         .await?;
@@ -0,0 +1,603 @@
+// boot-rust/src/prompts.rs
+
+use anyhow::{anyhow, Result};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use tracing::{info, warn};
+
+use crate::error::BootError;
+
+/// Env var selecting how prompt files are loaded: `reload` (default) or `cache`.
+const PROMPTS_MODE_ENV: &str = "BOOT_PROMPTS_MODE";
+
+/// Env var holding a comma-separated list of glob patterns for prompt files to skip.
+const PROMPTS_IGNORE_ENV: &str = "BOOT_PROMPTS_IGNORE";
+
+/// Env var naming an extra prompts directory whose files override the built-in ones.
+const EXTRA_PROMPTS_DIR_ENV: &str = "BOOT_EXTRA_PROMPTS_DIR";
+
+/// Env var holding the expected checksum of all prompt components.
+const PROMPTS_CHECKSUM_ENV: &str = "BOOT_PROMPTS_CHECKSUM";
+
+/// Env var making a checksum mismatch fatal (`strict`) instead of a warning (`warn`, default).
+const PROMPTS_CHECKSUM_MODE_ENV: &str = "BOOT_PROMPTS_CHECKSUM_MODE";
+
+/// Prompt files skipped when `BOOT_PROMPTS_IGNORE` is unset.
+const DEFAULT_IGNORE: &[&str] = &["Dockerfile"];
+
+/// How the prompts directory is read across requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PromptsMode {
+    /// Re-read the prompts directory on every request so edits show up immediately.
+    #[default]
+    Reload,
+    /// Read the prompts directory once and serve the cached components afterwards.
+    Cache,
+}
+
+impl PromptsMode {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            PromptsMode::Reload => "reload",
+            PromptsMode::Cache => "cache",
+        }
+    }
+
+    pub fn from_env() -> Self {
+        match env::var(PROMPTS_MODE_ENV) {
+            Ok(value) => match value.trim().to_ascii_lowercase().as_str() {
+                "" | "reload" => PromptsMode::Reload,
+                "cache" => PromptsMode::Cache,
+                other => {
+                    warn!(
+                        "Unknown {} value {:?}; falling back to 'reload'",
+                        PROMPTS_MODE_ENV, other
+                    );
+                    PromptsMode::Reload
+                }
+            },
+            Err(_) => PromptsMode::Reload,
+        }
+    }
+}
+
+/// Glob patterns for prompt files that should not be served.
+///
+/// Patterns support `*` and `?`. A pattern containing `/` is matched against the
+/// file's path relative to the prompts directory; otherwise against its basename.
+#[derive(Debug, Clone)]
+pub struct IgnoreList {
+    patterns: Vec<String>,
+}
+
+impl Default for IgnoreList {
+    fn default() -> Self {
+        Self::new(DEFAULT_IGNORE.iter().map(|p| p.to_string()).collect())
+    }
+}
+
+impl IgnoreList {
+    pub fn new(patterns: Vec<String>) -> Self {
+        Self { patterns }
+    }
+
+    /// Reads `BOOT_PROMPTS_IGNORE`; an empty value disables ignoring entirely.
+    pub fn from_env() -> Self {
+        match env::var(PROMPTS_IGNORE_ENV) {
+            Ok(value) => Self::new(
+                value
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|p| !p.is_empty())
+                    .map(str::to_string)
+                    .collect(),
+            ),
+            Err(_) => Self::default(),
+        }
+    }
+
+    pub fn is_ignored(&self, key: &str) -> bool {
+        let basename = key.rsplit('/').next().unwrap_or(key);
+        self.patterns.iter().any(|pattern| {
+            let target = if pattern.contains('/') { key } else { basename };
+            glob_match(pattern, target)
+        })
+    }
+}
+
+/// Prompt files read from disk, plus any non-fatal issues hit while reading them.
+#[derive(Debug, Clone, Default)]
+pub struct PromptComponents {
+    pub files: HashMap<String, String>,
+    pub warnings: Vec<String>,
+}
+
+impl PromptComponents {
+    /**
+     * A stable 64-bit FNV-1a hash over every component's path and content,
+     * formatted as 16 hex digits. Identical prompt sets always hash the same,
+     * regardless of directory iteration order.
+     */
+    pub fn checksum(&self) -> String {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let mut keys: Vec<&String> = self.files.keys().collect();
+        keys.sort();
+
+        let mut hash = FNV_OFFSET;
+        let mut feed = |bytes: &[u8]| {
+            for &b in bytes {
+                hash ^= u64::from(b);
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        };
+        for key in keys {
+            feed(key.as_bytes());
+            feed(&[0]);
+            feed(self.files[key].as_bytes());
+            feed(&[0]);
+        }
+        format!("{:016x}", hash)
+    }
+}
+
+/// The checksum operators expect the loaded prompts to have.
+#[derive(Debug, Clone)]
+pub struct ExpectedChecksum {
+    pub value: String,
+    /// Fail on a mismatch instead of only logging a warning.
+    pub strict: bool,
+}
+
+impl ExpectedChecksum {
+    pub fn from_env() -> Option<Self> {
+        let value = env::var(PROMPTS_CHECKSUM_ENV)
+            .ok()?
+            .trim()
+            .to_ascii_lowercase();
+        if value.is_empty() {
+            return None;
+        }
+        let strict = match env::var(PROMPTS_CHECKSUM_MODE_ENV) {
+            Ok(mode) => match mode.trim().to_ascii_lowercase().as_str() {
+                "" | "warn" => false,
+                "strict" => true,
+                other => {
+                    warn!(
+                        "Unknown {} value {:?}; falling back to 'warn'",
+                        PROMPTS_CHECKSUM_MODE_ENV, other
+                    );
+                    false
+                }
+            },
+            Err(_) => false,
+        };
+        Some(Self { value, strict })
+    }
+
+    /**
+     * Compares `components` against the expected checksum. A mismatch is an
+     * error in strict mode; otherwise it is logged and added to the
+     * components' warnings.
+     */
+    pub fn verify(&self, components: &mut PromptComponents) -> Result<(), BootError> {
+        let checksum = components.checksum();
+        if self.value == checksum {
+            return Ok(());
+        }
+        let message = format!(
+            "prompt checksum {} does not match {}={}",
+            checksum, PROMPTS_CHECKSUM_ENV, self.value
+        );
+        if self.strict {
+            return Err(BootError::PromptsChecksumMismatch(message));
+        }
+        warn!("{}", message);
+        components.warnings.push(message);
+        Ok(())
+    }
+}
+
+/// Loads prompt components from disk according to a [`PromptsMode`].
+#[derive(Debug, Default)]
+pub struct PromptStore {
+    mode: PromptsMode,
+    ignore: IgnoreList,
+    extra_dir: Option<PathBuf>,
+    expected_checksum: Option<ExpectedChecksum>,
+    cached: Mutex<Option<PromptComponents>>,
+}
+
+impl PromptStore {
+    pub fn new(
+        mode: PromptsMode,
+        ignore: IgnoreList,
+        extra_dir: Option<PathBuf>,
+        expected_checksum: Option<ExpectedChecksum>,
+    ) -> Self {
+        Self {
+            mode,
+            ignore,
+            extra_dir,
+            expected_checksum,
+            cached: Mutex::new(None),
+        }
+    }
+
+    pub fn from_env() -> Self {
+        let mode = PromptsMode::from_env();
+        let ignore = IgnoreList::from_env();
+        let extra_dir = env::var_os(EXTRA_PROMPTS_DIR_ENV)
+            .filter(|dir| !dir.is_empty())
+            .map(PathBuf::from);
+        info!(
+            "Prompt loading mode: {:?}, ignoring: {:?}, extra prompts dir: {:?}",
+            mode, ignore.patterns, extra_dir
+        );
+        Self::new(mode, ignore, extra_dir, ExpectedChecksum::from_env())
+    }
+
+    /**
+     * Returns the prompt components, keyed by their path relative to `prompts/`.
+     *
+     * In `Cache` mode the directory is only read on the first successful call.
+     */
+    pub fn components(&self) -> Result<PromptComponents, BootError> {
+        if self.mode == PromptsMode::Reload {
+            return self.load();
+        }
+
+        let mut cached = self
+            .cached
+            .lock()
+            .map_err(|_| BootError::Internal("Prompt cache lock was poisoned".to_string()))?;
+        if let Some(components) = cached.as_ref() {
+            return Ok(components.clone());
+        }
+        let components = self.load()?;
+        *cached = Some(components.clone());
+        Ok(components)
+    }
+
+    pub fn mode(&self) -> PromptsMode {
+        self.mode
+    }
+
+    pub fn extra_dir(&self) -> Option<&Path> {
+        self.extra_dir.as_deref()
+    }
+
+    /**
+     * Loads the prompts once so a missing or unreadable prompts directory is
+     * reported at startup instead of on the first request. In `Cache` mode
+     * this also fills the cache.
+     *
+     * The combined checksum is logged so operators can pin it with
+     * `BOOT_PROMPTS_CHECKSUM`.
+     */
+    pub fn warm_up(&self) -> Result<(), BootError> {
+        let components = self.components()?;
+        info!(
+            "Loaded {} prompt components (checksum {})",
+            components.files.len(),
+            components.checksum()
+        );
+        Ok(())
+    }
+
+    /**
+     * Loads the built-in prompts, then overlays the extra directory if one is set.
+     *
+     * When `BOOT_PROMPTS_CHECKSUM` is set the result is verified on every load,
+     * so in `Reload` mode files changed after startup are checked too.
+     */
+    fn load(&self) -> Result<PromptComponents, BootError> {
+        let prompts_dir = get_prompts_path().map_err(BootError::prompts_not_found)?;
+        self.load_from(&prompts_dir)
+    }
+
+    /// Loads `prompts_dir` as the built-in prompts; see [`load`](Self::load).
+    fn load_from(&self, prompts_dir: &Path) -> Result<PromptComponents, BootError> {
+        let mut components =
+            load_prompt_components(prompts_dir, &self.ignore).map_err(BootError::prompts)?;
+
+        if let Some(extra_dir) = &self.extra_dir {
+            if !extra_dir.is_dir() {
+                return Err(BootError::PromptsNotFound(format!(
+                    "{} is set to {}, which is not a directory",
+                    EXTRA_PROMPTS_DIR_ENV,
+                    extra_dir.display()
+                )));
+            }
+            let extra =
+                load_prompt_components(extra_dir, &self.ignore).map_err(BootError::prompts)?;
+            components.files.extend(extra.files);
+            components.warnings.extend(extra.warnings);
+        }
+
+        if let Some(expected) = &self.expected_checksum {
+            expected.verify(&mut components)?;
+        }
+        Ok(components)
+    }
+}
+
+/**
+ * Gets the absolute path to the 'prompts' directory for local development.
+ *
+ * This function assumes a sibling-directory layout (`boot-core/` and `boot-rust/`)
+ * and constructs the path from the current working directory.
+ *
+ * @returns The absolute path to the `boot-rust/prompts` directory.
+ */
+pub fn get_prompts_path() -> Result<PathBuf> {
+    // Get the current working directory from where `boot` was run (e.g., /path/to/boot-core)
+    let current_dir = env::current_dir()?;
+
+    // Go up one level to the parent workspace directory
+    let workspace_dir = current_dir
+        .parent()
+        .ok_or_else(|| anyhow!("Failed to get parent directory of {:?}", current_dir))?;
+
+    // Construct the path to the sibling `boot-rust/prompts` directory
+    let prompts_path = workspace_dir.join("boot-rust").join("prompts");
+
+    if !prompts_path.is_dir() {
+        return Err(anyhow!(
+            "Could not find 'prompts' directory at expected dev path: {}. Ensure boot-rust is a sibling to boot-core.",
+            prompts_path.display()
+        ));
+    }
+    Ok(prompts_path)
+}
+
+/**
+ * Reads every prompt file under `prompts_dir`, including subdirectories, into
+ * a map keyed by its `/`-separated path relative to `prompts_dir`. Files
+ * matching `ignore` are skipped.
+ */
+pub fn load_prompt_components(prompts_dir: &Path, ignore: &IgnoreList) -> Result<PromptComponents> {
+    let mut components = PromptComponents::default();
+    collect_prompt_files(prompts_dir, prompts_dir, ignore, &mut components)?;
+    Ok(components)
+}
+
+fn collect_prompt_files(
+    root: &Path,
+    dir: &Path,
+    ignore: &IgnoreList,
+    components: &mut PromptComponents,
+) -> Result<()> {
+    let entries = fs::read_dir(dir)
+        .map_err(|e| anyhow!("Could not read prompts directory {:?}: {}", dir, e))?;
+
+    for entry in entries {
+        let entry = entry.map_err(|e| anyhow!("Invalid directory entry: {}", e))?;
+        let path = entry.path();
+        // `file_type` does not follow symlinks, so a linked directory can't cause a cycle.
+        let file_type = entry
+            .file_type()
+            .map_err(|e| anyhow!("Could not stat {:?}: {}", path, e))?;
+        if file_type.is_dir() {
+            collect_prompt_files(root, &path, ignore, components)?;
+            continue;
+        }
+        if !path.is_file() {
+            continue;
+        }
+        let Some(key) = component_key(root, &path) else {
+            let warning = format!("Skipped prompt file with a non-UTF-8 path: {:?}", path);
+            warn!("{}", warning);
+            components.warnings.push(warning);
+            continue;
+        };
+        if ignore.is_ignored(&key) {
+            continue;
+        }
+        let content = fs::read_to_string(&path)
+            .map_err(|e| anyhow!("Could not read file {:?}: {}", path, e))?;
+        components.files.insert(key, content);
+    }
+    Ok(())
+}
+
+/// Builds the component key for `path`, e.g. `examples/cli.txt`.
+fn component_key(root: &Path, path: &Path) -> Option<String> {
+    let relative = path.strip_prefix(root).ok()?;
+    let parts = relative
+        .components()
+        .map(|c| c.as_os_str().to_str())
+        .collect::<Option<Vec<_>>>()?;
+    Some(parts.join("/"))
+}
+
+/// Matches `text` against a glob `pattern` supporting `*` and `?`.
+fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Position of the last `*` seen and the text index it was tried at.
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn glob_match_handles_wildcards() {
+        assert!(glob_match("*.md", "README.md"));
+        assert!(!glob_match("*.md", "README.mdx"));
+        assert!(glob_match("?ockerfile", "Dockerfile"));
+        assert!(glob_match("a*b*c", "axxbyybc"));
+        assert!(glob_match("*", ""));
+        assert!(!glob_match("a", ""));
+        assert!(glob_match("Dockerfile", "Dockerfile"));
+        assert!(!glob_match("Dockerfile", "Dockerfile.dev"));
+    }
+
+    #[test]
+    fn ignore_list_matches_basename_or_relative_path() {
+        let ignore = IgnoreList::new(vec!["*.bak".to_string(), "examples/*".to_string()]);
+        assert!(ignore.is_ignored("notes.bak"));
+        assert!(ignore.is_ignored("nested/notes.bak"));
+        assert!(ignore.is_ignored("examples/cli.txt"));
+        assert!(!ignore.is_ignored("nested/examples/cli.txt"));
+        assert!(!ignore.is_ignored("base_instructions.txt"));
+    }
+
+    fn components(files: &[(&str, &str)]) -> PromptComponents {
+        PromptComponents {
+            files: files
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            warnings: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn checksum_is_independent_of_insertion_order() {
+        let a = components(&[("a.txt", "one"), ("b/c.txt", "two"), ("d.txt", "three")]);
+        let b = components(&[("d.txt", "three"), ("a.txt", "one"), ("b/c.txt", "two")]);
+        assert_eq!(a.checksum(), b.checksum());
+        assert_eq!(a.checksum().len(), 16);
+    }
+
+    #[test]
+    fn checksum_changes_with_content_and_path_boundaries() {
+        let base = components(&[("ab", "c")]);
+        assert_ne!(base.checksum(), components(&[("ab", "d")]).checksum());
+        assert_ne!(base.checksum(), components(&[("a", "bc")]).checksum());
+        assert_ne!(base.checksum(), components(&[]).checksum());
+    }
+
+    #[test]
+    fn checksum_mismatch_warns_or_fails_by_mode() {
+        let mut prompts = components(&[("a.txt", "one")]);
+        let matching = ExpectedChecksum {
+            value: prompts.checksum(),
+            strict: true,
+        };
+        assert!(matching.verify(&mut prompts).is_ok());
+        assert!(prompts.warnings.is_empty());
+
+        let lenient = ExpectedChecksum {
+            value: "0000000000000000".to_string(),
+            strict: false,
+        };
+        assert!(lenient.verify(&mut prompts).is_ok());
+        assert_eq!(prompts.warnings.len(), 1);
+
+        let strict = ExpectedChecksum {
+            strict: true,
+            ..lenient
+        };
+        let err = strict.verify(&mut prompts).unwrap_err();
+        assert_eq!(err.reason(), "PROMPTS_CHECKSUM_MISMATCH");
+    }
+
+    fn sorted_keys(components: &PromptComponents) -> Vec<&str> {
+        let mut keys: Vec<&str> = components.files.keys().map(String::as_str).collect();
+        keys.sort();
+        keys
+    }
+
+    #[test]
+    fn loads_nested_prompts_keyed_by_relative_path() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("base.txt"), "base").unwrap();
+        fs::create_dir(dir.path().join("examples")).unwrap();
+        fs::write(dir.path().join("examples/cli.txt"), "cli").unwrap();
+        fs::write(dir.path().join("examples/Dockerfile"), "FROM rust").unwrap();
+
+        let components = load_prompt_components(dir.path(), &IgnoreList::default()).unwrap();
+        assert_eq!(sorted_keys(&components), ["base.txt", "examples/cli.txt"]);
+        assert_eq!(components.files["examples/cli.txt"], "cli");
+        assert!(components.warnings.is_empty(), "{:?}", components.warnings);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn skips_symlinked_directories() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::create_dir(dir.path().join("examples")).unwrap();
+        fs::write(dir.path().join("examples/cli.txt"), "cli").unwrap();
+        std::os::unix::fs::symlink(dir.path(), dir.path().join("examples/loop")).unwrap();
+
+        let components = load_prompt_components(dir.path(), &IgnoreList::default()).unwrap();
+        assert_eq!(sorted_keys(&components), ["examples/cli.txt"]);
+    }
+
+    #[test]
+    fn extra_dir_overrides_built_ins_on_matching_path() {
+        let built_in = tempfile::tempdir().unwrap();
+        fs::write(built_in.path().join("base.txt"), "built-in base").unwrap();
+        fs::create_dir(built_in.path().join("examples")).unwrap();
+        fs::write(built_in.path().join("examples/cli.txt"), "built-in cli").unwrap();
+        let extra = tempfile::tempdir().unwrap();
+        fs::create_dir(extra.path().join("examples")).unwrap();
+        fs::write(extra.path().join("examples/cli.txt"), "extra cli").unwrap();
+        fs::write(extra.path().join("house_style.txt"), "extra style").unwrap();
+
+        let store = PromptStore::new(
+            PromptsMode::Reload,
+            IgnoreList::default(),
+            Some(extra.path().to_path_buf()),
+            None,
+        );
+        let components = store.load_from(built_in.path()).unwrap();
+        assert_eq!(
+            sorted_keys(&components),
+            ["base.txt", "examples/cli.txt", "house_style.txt"]
+        );
+        assert_eq!(components.files["base.txt"], "built-in base");
+        assert_eq!(components.files["examples/cli.txt"], "extra cli");
+    }
+
+    #[test]
+    fn extra_dir_that_is_not_a_directory_is_an_error() {
+        let built_in = tempfile::tempdir().unwrap();
+        let file = built_in.path().join("base.txt");
+        fs::write(&file, "base").unwrap();
+
+        let store = PromptStore::new(PromptsMode::Reload, IgnoreList::default(), Some(file), None);
+        let err = store.load_from(built_in.path()).unwrap_err();
+        assert_eq!(err.reason(), "PROMPTS_NOT_FOUND");
+        assert!(err.to_string().contains(EXTRA_PROMPTS_DIR_ENV), "{}", err);
+    }
+
+    #[test]
+    fn default_ignore_list_skips_dockerfiles_only() {
+        let ignore = IgnoreList::default();
+        assert!(ignore.is_ignored("Dockerfile"));
+        assert!(ignore.is_ignored("nested/Dockerfile"));
+        assert!(!ignore.is_ignored("Makefile"));
+    }
+}
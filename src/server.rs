@@ -1,67 +1,127 @@
 // boot-rust/src/server.rs
 
-use anyhow::{anyhow, Result};
-use std::collections::HashMap;
-use std::env;
-use std::fs;
-use std::path::PathBuf;
-use toml::Value;
+use anyhow::Result;
+use std::sync::atomic::{AtomicU64, Ordering};
+use tonic::metadata::MetadataValue;
 use tonic::{Request, Response, Status};
+use tracing::{info, info_span, warn};
 
 use crate::boot_plugin::{
-    boot_code_plugin_server::BootCodePlugin, GetPromptComponentsRequest, GetPromptComponentsResponse,
+    boot_code_plugin_server::BootCodePlugin, GetInfoRequest, GetInfoResponse,
+    GetPromptComponentsRequest, GetPromptComponentsResponse,
 };
+use crate::error::BootError;
+use crate::prompts::{get_prompts_path, PromptStore};
+use crate::spec::SpexSpecification;
 
 #[derive(Debug, Default)]
-pub struct MyBootCodePlugin {}
+pub struct MyBootCodePlugin {
+    prompts: PromptStore,
+}
+
+impl MyBootCodePlugin {
+    pub fn new(prompts: PromptStore) -> Self {
+        Self { prompts }
+    }
+}
+
+/// The language this plugin serves prompts for (matches `plugin.toml`).
+const PLUGIN_LANGUAGE: &str = "rust";
+
+/// Metadata keys checked, in order, for a host-supplied correlation ID.
+const REQUEST_ID_KEYS: [&str; 2] = ["x-request-id", "x-correlation-id"];
+
+static NEXT_REQUEST_ID: AtomicU64 = AtomicU64::new(1);
 
 /**
- * Gets the absolute path to the 'prompts' directory for local development.
- *
- * This function assumes a sibling-directory layout (`boot-core/` and `boot-rust/`)
- * and constructs the path from the current working directory.
- *
- * @returns The absolute path to the `boot-rust/prompts` directory.
+ * Returns the caller's correlation ID and the metadata key it was sent under,
+ * so the response can echo it back under the same key. Without one, a new
+ * process-local ID is returned under `x-request-id`.
  */
-fn get_prompts_path() -> Result<PathBuf> {
-    // Get the current working directory from where `boot` was run (e.g., /path/to/boot-core)
-    let current_dir = env::current_dir()?;
-
-    // Go up one level to the parent workspace directory
-    let workspace_dir = current_dir
-        .parent()
-        .ok_or_else(|| anyhow!("Failed to get parent directory of {:?}", current_dir))?;
-
-    // Construct the path to the sibling `boot-rust/prompts` directory
-    let prompts_path = workspace_dir.join("boot-rust").join("prompts");
-
-    if !prompts_path.is_dir() {
-        return Err(anyhow!(
-            "Could not find 'prompts' directory at expected dev path: {}. Ensure boot-rust is a sibling to boot-core.",
-            prompts_path.display()
-        ));
-    }
-    Ok(prompts_path)
+fn request_id<T>(request: &Request<T>) -> (&'static str, String) {
+    REQUEST_ID_KEYS
+        .iter()
+        .find_map(|&key| {
+            request
+                .metadata()
+                .get(key)
+                .and_then(|v| v.to_str().ok())
+                .map(str::trim)
+                .filter(|v| !v.is_empty())
+                .map(|v| (key, v.to_string()))
+        })
+        .unwrap_or_else(|| {
+            let id = format!(
+                "boot-rust-{}-{}",
+                std::process::id(),
+                NEXT_REQUEST_ID.fetch_add(1, Ordering::Relaxed)
+            );
+            (REQUEST_ID_KEYS[0], id)
+        })
 }
 
-// format_spec_for_prompt function remains the same...
-fn format_spec_for_prompt(spec_toml_content: &str) -> Result<String> {
-    let spec: Value = toml::from_str(spec_toml_content)?;
+fn format_spec_for_prompt(spec_toml_content: &str, warnings: &mut Vec<String>) -> Result<String> {
+    let spec = SpexSpecification::from_toml_str(spec_toml_content)?;
+    let project_name = spec.project_name().unwrap_or("Unnamed project");
     let description = spec
-        .get("description")
-        .and_then(Value::as_str)
+        .description
+        .as_deref()
         .unwrap_or("No description provided.");
-    let project_name = spec
-        .get("project")
-        .and_then(|p| p.get("name"))
-        .and_then(Value::as_str)
-        .unwrap_or("Unnamed project");
-    Ok(format!(
+    let mut prompt = format!(
         "--- USER SPECIFICATION ---\nProject Name: {}\nDescription: {}",
         project_name, description
-    ))
-}
+    );
+    if let Some(project_type) = &spec.project_type {
+        prompt.push_str(&format!("\nProject Type: {}", project_type));
+    }
+    let foreign_language = spec
+        .language
+        .as_deref()
+        .filter(|_| spec.language_key().as_deref() != Some(PLUGIN_LANGUAGE));
+    if let Some(language) = foreign_language {
+        let warning = format!(
+            "Spec language is '{}' but this plugin provides {} prompts",
+            language, PLUGIN_LANGUAGE
+        );
+        warn!("{}", warning);
+        warnings.push(warning);
+    }
+
+    let features = spec.features(warnings);
+    if !features.is_empty() {
+        prompt.push_str("\nFeatures:");
+        for feature in &features {
+            if feature.description.is_empty() {
+                prompt.push_str(&format!("\n- {}", feature.name));
+            } else {
+                prompt.push_str(&format!("\n- {}: {}", feature.name, feature.description));
+            }
+            if !feature.files.is_empty() {
+                prompt.push_str(&format!(" (files: {})", feature.files.join(", ")));
+            }
+        }
+    }
 
+    let dependencies = spec.dependencies(warnings);
+    if !dependencies.is_empty() {
+        prompt.push_str("\nRequired Dependencies (include these in Cargo.toml):");
+        for dep in &dependencies {
+            prompt.push_str(&format!("\n- {} = \"{}\"", dep.name, dep.version));
+            if !dep.features.is_empty() {
+                prompt.push_str(&format!(" (features: {})", dep.features.join(", ")));
+            }
+        }
+    }
+
+    let files = spec.files(warnings);
+    if !files.is_empty() {
+        prompt.push_str("\nFiles to Generate (produce exactly these files and no others):");
+        for path in &files {
+            prompt.push_str(&format!("\n- {}", path));
+        }
+    }
+    Ok(prompt)
+}
 
 #[tonic::async_trait]
 impl BootCodePlugin for MyBootCodePlugin {
@@ -69,35 +129,164 @@ impl BootCodePlugin for MyBootCodePlugin {
         &self,
         request: Request<GetPromptComponentsRequest>,
     ) -> Result<Response<GetPromptComponentsResponse>, Status> {
-        let spec_content = &request.get_ref().spec_toml_content;
-
-        let mut components = HashMap::new();
-        let prompts_dir =
-            get_prompts_path().map_err(|e| Status::internal(e.to_string()))?;
-        let entries = fs::read_dir(&prompts_dir)
-            .map_err(|e| Status::internal(format!("Could not read prompts directory: {}", e)))?;
-
-        for entry in entries {
-            let entry = entry.map_err(|e| Status::internal(format!("Invalid directory entry: {}", e)))?;
-            let path = entry.path();
-            if path.is_file() {
-                if let Some(file_name) = path.file_name().and_then(|s| s.to_str()) {
-                    if file_name == "Dockerfile" {
-                        continue;
-                    }
-                    let content = fs::read_to_string(&path)
-                        .map_err(|e| Status::internal(format!("Could not read file {:?}: {}", path, e)))?;
-                    components.insert(file_name.to_string(), content);
-                }
-            }
+        let (request_id_key, request_id) = request_id(&request);
+        let span = info_span!("get_prompt_components", request_id = %request_id);
+
+        let response = span
+            .in_scope(|| -> Result<GetPromptComponentsResponse, BootError> {
+                let spec_content = &request.get_ref().spec_toml_content;
+
+                let prompts = self.prompts.components()?;
+                info!("Serving {} prompt components", prompts.files.len());
+
+                let mut warnings = prompts.warnings;
+                let user_spec_prompt =
+                    format_spec_for_prompt(spec_content, &mut warnings).map_err(BootError::spec)?;
+
+                Ok(GetPromptComponentsResponse {
+                    components: prompts.files,
+                    user_spec_prompt,
+                    warnings,
+                })
+            })
+            .map_err(Status::from)?;
+
+        let mut response = Response::new(response);
+        if let Ok(value) = MetadataValue::try_from(request_id.as_str()) {
+            response.metadata_mut().insert(request_id_key, value);
         }
+        Ok(response)
+    }
 
-        let response = GetPromptComponentsResponse {
-            components,
-            user_spec_prompt: format_spec_for_prompt(spec_content)
-                .map_err(|e| Status::internal(e.to_string()))?,
-        };
+    async fn get_info(
+        &self,
+        _request: Request<GetInfoRequest>,
+    ) -> Result<Response<GetInfoResponse>, Status> {
+        let prompts_dir = get_prompts_path()
+            .map(|p| p.display().to_string())
+            .unwrap_or_default();
 
-        Ok(Response::new(response))
+        Ok(Response::new(GetInfoResponse {
+            name: env!("CARGO_PKG_NAME").to_string(),
+            version: env!("CARGO_PKG_VERSION").to_string(),
+            language: PLUGIN_LANGUAGE.to_string(),
+            prompts_dir,
+            prompts_mode: self.prompts.mode().as_str().to_string(),
+            extra_prompts_dir: self
+                .prompts
+                .extra_dir()
+                .map(|dir| dir.display().to_string())
+                .unwrap_or_default(),
+        }))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reuses_the_callers_request_id_under_its_key() {
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert("x-correlation-id", MetadataValue::from_static(" abc-123 "));
+        assert_eq!(
+            request_id(&request),
+            ("x-correlation-id", "abc-123".to_string())
+        );
+
+        request
+            .metadata_mut()
+            .insert("x-request-id", MetadataValue::from_static("req-1"));
+        assert_eq!(request_id(&request), ("x-request-id", "req-1".to_string()));
+    }
+
+    #[test]
+    fn generates_a_fresh_request_id_when_none_is_sent() {
+        let mut request = Request::new(());
+        request
+            .metadata_mut()
+            .insert("x-request-id", MetadataValue::from_static("  "));
+        let (key, first) = request_id(&request);
+        let (_, second) = request_id(&request);
+        assert_eq!(key, "x-request-id");
+        assert!(first.starts_with("boot-rust-"), "{}", first);
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn formats_features_dependencies_and_files() {
+        let spec = r#"
+description = "A todo list CLI."
+language = "rust"
+project_type = "cli"
+files = ["src/main.rs", "src/add.rs"]
+
+[project]
+name = "todo"
+
+[[features]]
+name = "add"
+description = "Add a todo item"
+files = ["src/add.rs"]
+
+[[features]]
+name = "list"
+
+[[dependencies]]
+name = "clap"
+version = "4"
+features = ["derive"]
+
+[[dependencies]]
+name = "anyhow"
+version = "1"
+"#;
+        let mut warnings = Vec::new();
+        let prompt = format_spec_for_prompt(spec, &mut warnings).unwrap();
+        assert_eq!(
+            prompt,
+            "--- USER SPECIFICATION ---\n\
+             Project Name: todo\n\
+             Description: A todo list CLI.\n\
+             Project Type: cli\n\
+             Features:\n\
+             - add: Add a todo item (files: src/add.rs)\n\
+             - list\n\
+             Required Dependencies (include these in Cargo.toml):\n\
+             - clap = \"4\" (features: derive)\n\
+             - anyhow = \"1\"\n\
+             Files to Generate (produce exactly these files and no others):\n\
+             - src/main.rs\n\
+             - src/add.rs"
+        );
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn falls_back_for_missing_name_and_description() {
+        let mut warnings = Vec::new();
+        let prompt = format_spec_for_prompt("[project]\nname = \" \"", &mut warnings).unwrap();
+        assert_eq!(
+            prompt,
+            "--- USER SPECIFICATION ---\n\
+             Project Name: Unnamed project\n\
+             Description: No description provided."
+        );
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn warns_about_a_foreign_language() {
+        let mut warnings = Vec::new();
+        format_spec_for_prompt("language = \" Rust \"", &mut warnings).unwrap();
+        assert!(warnings.is_empty(), "{:?}", warnings);
+
+        format_spec_for_prompt("language = \"Python\"", &mut warnings).unwrap();
+        assert_eq!(
+            warnings,
+            ["Spec language is 'Python' but this plugin provides rust prompts"]
+        );
+    }
+}
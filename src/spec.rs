@@ -0,0 +1,346 @@
+// boot-rust/src/spec.rs
+
+use anyhow::Result;
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use toml::{Table, Value};
+use tracing::warn;
+
+/// The `[project]` table of a user's `spec.toml`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ProjectInfo {
+    #[serde(default)]
+    pub name: Option<String>,
+}
+
+/// A single entry of the spec's `[[features]]` array.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Feature {
+    pub name: String,
+    #[serde(default)]
+    pub description: String,
+    /// Files implementing this feature, so the model knows each file's purpose.
+    #[serde(default)]
+    pub files: Vec<String>,
+}
+
+/// A single entry of the spec's `[[dependencies]]` array.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Dependency {
+    pub name: String,
+    pub version: String,
+    #[serde(default)]
+    pub features: Vec<String>,
+}
+
+/// A typed view of the `spec.toml` content sent by `boot-core`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SpexSpecification {
+    #[serde(default)]
+    pub project: Option<ProjectInfo>,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub project_type: Option<String>,
+    /// Everything not modelled above (e.g. `[[features]]`, `[[dependencies]]`, `files`).
+    #[serde(flatten)]
+    pub extras: Table,
+}
+
+impl SpexSpecification {
+    /**
+     * Parses the raw `spec.toml` content received over gRPC.
+     *
+     * Every field is optional, so only malformed TOML or wrongly typed known
+     * fields are reported as errors. The result is already
+     * [normalized](Self::normalize).
+     */
+    pub fn from_toml_str(content: &str) -> Result<Self> {
+        let mut spec: Self = toml::from_str(content)?;
+        spec.normalize();
+        Ok(spec)
+    }
+
+    /**
+     * Trims user-facing fields, keeping their original casing for display.
+     * Blank values become `None`, so a blank project name gets the same
+     * fallback as a missing one.
+     */
+    pub fn normalize(&mut self) {
+        if let Some(project) = &mut self.project {
+            project.name = normalize_field(project.name.take());
+        }
+        self.description = normalize_field(self.description.take());
+        self.language = normalize_field(self.language.take());
+        self.project_type = normalize_field(self.project_type.take());
+    }
+
+    /// The trimmed, lowercased `language`, so "Rust " and "rust" match alike.
+    pub fn language_key(&self) -> Option<String> {
+        self.language
+            .as_deref()
+            .map(|language| language.trim().to_lowercase())
+    }
+
+    /// The `[project].name`, if the spec sets one.
+    pub fn project_name(&self) -> Option<&str> {
+        self.project.as_ref()?.name.as_deref()
+    }
+
+    /**
+     * Returns the entries of the `[[features]]` array in `extras`.
+     *
+     * Malformed entries are skipped and reported in `warnings` rather than
+     * failing the whole request.
+     */
+    pub fn features(&self, warnings: &mut Vec<String>) -> Vec<Feature> {
+        self.parse_extras_array("features", "feature", warnings)
+    }
+
+    /**
+     * Returns the entries of the `[[dependencies]]` array in `extras`.
+     *
+     * Entries that are malformed or have an empty `version` are skipped and
+     * reported in `warnings`.
+     */
+    pub fn dependencies(&self, warnings: &mut Vec<String>) -> Vec<Dependency> {
+        self.parse_extras_array::<Dependency>("dependencies", "dependency", warnings)
+            .into_iter()
+            .filter(|dep| {
+                if dep.version.trim().is_empty() {
+                    let warning = format!("Skipped dependency '{}': empty version", dep.name);
+                    warn!("{}", warning);
+                    warnings.push(warning);
+                    return false;
+                }
+                true
+            })
+            .collect()
+    }
+
+    /**
+     * Returns the trimmed, non-blank paths of the `files` array in `extras`:
+     * the exact output paths the model should produce, when the user wants
+     * to pin the file set.
+     *
+     * Like `[[features]]`, a wrongly typed `files` or non-string entry is
+     * skipped and reported in `warnings` rather than failing the request.
+     */
+    pub fn files(&self, warnings: &mut Vec<String>) -> Vec<String> {
+        self.parse_extras_array::<String>("files", "file", warnings)
+            .into_iter()
+            .filter_map(|path| normalize_field(Some(path)))
+            .collect()
+    }
+
+    fn parse_extras_array<T: DeserializeOwned>(
+        &self,
+        key: &str,
+        label: &str,
+        warnings: &mut Vec<String>,
+    ) -> Vec<T> {
+        let entries = match self.extras.get(key) {
+            None => return Vec::new(),
+            Some(Value::Array(entries)) => entries,
+            Some(other) => {
+                let warning = format!(
+                    "Ignored '{}': expected an array, found {}",
+                    key,
+                    other.type_str()
+                );
+                warn!("{}", warning);
+                warnings.push(warning);
+                return Vec::new();
+            }
+        };
+        entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| match entry.clone().try_into::<T>() {
+                Ok(item) => Some(item),
+                Err(e) => {
+                    let warning = format!("Skipped malformed {} #{}: {}", label, i, e.message());
+                    warn!("{}", warning);
+                    warnings.push(warning);
+                    None
+                }
+            })
+            .collect()
+    }
+}
+
+/// Trims `value`; blank values become `None`.
+fn normalize_field(value: Option<String>) -> Option<String> {
+    let value = value?;
+    let trimmed = value.trim();
+    if trimmed.is_empty() {
+        None
+    } else {
+        Some(trimmed.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FULL_SPEC: &str = r#"
+description = "A todo list CLI."
+language = "rust"
+project_type = "cli"
+
+[project]
+name = "todo"
+
+[[features]]
+name = "add"
+description = "Add a todo item"
+files = ["src/add.rs"]
+
+[[dependencies]]
+name = "clap"
+version = "4"
+features = ["derive"]
+"#;
+
+    #[test]
+    fn parses_full_spec() {
+        let spec = SpexSpecification::from_toml_str(FULL_SPEC).unwrap();
+        assert_eq!(spec.project_name(), Some("todo"));
+        assert_eq!(spec.description.as_deref(), Some("A todo list CLI."));
+        assert_eq!(spec.language.as_deref(), Some("rust"));
+        assert_eq!(spec.language_key().as_deref(), Some("rust"));
+        assert_eq!(spec.project_type.as_deref(), Some("cli"));
+    }
+
+    #[test]
+    fn parse_errors_are_not_prefixed() {
+        let err = SpexSpecification::from_toml_str("language = 3").unwrap_err();
+        assert!(err.to_string().starts_with("TOML parse error"), "{}", err);
+    }
+
+    #[test]
+    fn missing_project_is_allowed() {
+        let spec = SpexSpecification::from_toml_str("description = \"x\"").unwrap();
+        assert_eq!(spec.project_name(), None);
+        assert_eq!(spec.description.as_deref(), Some("x"));
+
+        let spec = SpexSpecification::from_toml_str("[project]").unwrap();
+        assert_eq!(spec.project_name(), None);
+    }
+
+    #[test]
+    fn blank_project_name_is_treated_as_missing() {
+        let spec = SpexSpecification::from_toml_str("[project]\nname = \"   \"").unwrap();
+        assert_eq!(spec.project_name(), None);
+    }
+
+    #[test]
+    fn normalize_keeps_display_casing_and_lowercases_match_key() {
+        let spec = SpexSpecification::from_toml_str(
+            "language = \" Rust \"\nproject_type = \" CLI \"\ndescription = \"  \"\n[project]\nname = \" Todo \"",
+        )
+        .unwrap();
+        assert_eq!(spec.project_name(), Some("Todo"));
+        assert_eq!(spec.language.as_deref(), Some("Rust"));
+        assert_eq!(spec.language_key().as_deref(), Some("rust"));
+        assert_eq!(spec.project_type.as_deref(), Some("CLI"));
+        assert_eq!(spec.description, None);
+    }
+
+    #[test]
+    fn extracts_features_and_dependencies() {
+        let spec = SpexSpecification::from_toml_str(FULL_SPEC).unwrap();
+        let mut warnings = Vec::new();
+
+        let features = spec.features(&mut warnings);
+        assert_eq!(features.len(), 1);
+        assert_eq!(features[0].name, "add");
+        assert_eq!(features[0].description, "Add a todo item");
+        assert_eq!(features[0].files, ["src/add.rs"]);
+
+        let dependencies = spec.dependencies(&mut warnings);
+        assert_eq!(dependencies.len(), 1);
+        assert_eq!(dependencies[0].name, "clap");
+        assert_eq!(dependencies[0].version, "4");
+        assert_eq!(dependencies[0].features, ["derive"]);
+
+        assert!(warnings.is_empty(), "{:?}", warnings);
+    }
+
+    #[test]
+    fn skips_malformed_entries_with_warnings() {
+        let spec = SpexSpecification::from_toml_str(
+            r#"
+[project]
+name = "todo"
+
+[[features]]
+description = "no name"
+
+[[dependencies]]
+name = "serde"
+version = " "
+"#,
+        )
+        .unwrap();
+        let mut warnings = Vec::new();
+
+        assert!(spec.features(&mut warnings).is_empty());
+        assert!(spec.dependencies(&mut warnings).is_empty());
+        assert_eq!(warnings.len(), 2, "{:?}", warnings);
+        assert_eq!(
+            warnings[0],
+            "Skipped malformed feature #0: missing field `name`"
+        );
+    }
+
+    #[test]
+    fn warns_when_array_keys_have_the_wrong_type() {
+        let spec = SpexSpecification::from_toml_str(
+            r#"
+features = "auth"
+
+[project]
+name = "todo"
+
+[dependencies]
+tokio = "1"
+"#,
+        )
+        .unwrap();
+        let mut warnings = Vec::new();
+
+        assert!(spec.features(&mut warnings).is_empty());
+        assert!(spec.dependencies(&mut warnings).is_empty());
+        assert_eq!(
+            warnings,
+            [
+                "Ignored 'features': expected an array, found string",
+                "Ignored 'dependencies': expected an array, found table",
+            ]
+        );
+    }
+
+    #[test]
+    fn files_are_trimmed_and_malformed_entries_warned() {
+        let spec = SpexSpecification::from_toml_str(
+            "files = [\" src/main.rs \", \"  \", 3]\n[project]\nname = \"todo\"",
+        )
+        .unwrap();
+        let mut warnings = Vec::new();
+        assert_eq!(spec.files(&mut warnings), ["src/main.rs"]);
+        assert_eq!(warnings.len(), 1, "{:?}", warnings);
+
+        let spec =
+            SpexSpecification::from_toml_str("files = \"x\"\n[project]\nname = \"todo\"").unwrap();
+        let mut warnings = Vec::new();
+        assert!(spec.files(&mut warnings).is_empty());
+        assert_eq!(
+            warnings,
+            ["Ignored 'files': expected an array, found string"]
+        );
+    }
+}